@@ -0,0 +1,55 @@
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        system::{Commands, Query, Res},
+    },
+    math::{
+        curve::{Curve, EaseFunction, EasingCurve},
+        Vec3,
+    },
+    time::Time,
+    transform::components::Transform,
+};
+use std::time::Duration;
+
+/// Opt into tweened snapping: alongside a `GridCell`, `UpdateCellPosition` starts an
+/// [`AnimatingSnap`] instead of teleporting the `Transform` straight to its slot.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct GridSnapAnimation {
+    pub duration: Duration,
+    pub ease: EaseFunction,
+}
+
+/// In-flight tween toward a cell's slot, cleaned up by [`animate_snap`] once it completes.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct AnimatingSnap {
+    pub(crate) start: Vec3,
+    pub(crate) target: Vec3,
+    pub(crate) elapsed: Duration,
+    pub(crate) duration: Duration,
+    pub(crate) ease: EaseFunction,
+}
+
+pub(crate) fn animate_snap(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut animating_q: Query<(Entity, &mut Transform, &mut AnimatingSnap)>,
+) {
+    for (entity, mut transform, mut animating) in &mut animating_q {
+        animating.elapsed += time.delta();
+
+        let t = if animating.duration.is_zero() {
+            1.0
+        } else {
+            (animating.elapsed.as_secs_f32() / animating.duration.as_secs_f32()).clamp(0.0, 1.0)
+        };
+
+        transform.translation =
+            EasingCurve::new(animating.start, animating.target, animating.ease).sample_clamped(t);
+
+        if t >= 1.0 {
+            commands.entity(entity).remove::<AnimatingSnap>();
+        }
+    }
+}
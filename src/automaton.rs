@@ -0,0 +1,72 @@
+use bevy::{
+    ecs::{component::Component, entity::Entity, system::Query},
+    math::IVec2,
+};
+use std::collections::HashMap;
+
+use crate::{AttachedCells, Connectivity, GridCell};
+
+/// The state a [`GridCell`] carries for cellular-automata stepping.
+#[derive(Component, Clone)]
+pub struct CellState<S: Clone + Send + Sync + 'static>(pub S);
+
+/// Drives a cellular automaton over a `Grid`'s attached cells: [`step_automaton`] advances every
+/// [`CellState`]-bearing cell one generation via `rule`, double-buffered against the previous
+/// generation so no cell reads an already-advanced neighbor.
+#[derive(Component)]
+pub struct GridAutomaton<S: Clone + Send + Sync + 'static> {
+    pub connectivity: Connectivity,
+    rule: Box<dyn Fn(&S, &[S]) -> S + Send + Sync>,
+}
+
+impl<S: Clone + Send + Sync + 'static> GridAutomaton<S> {
+    pub fn new(
+        connectivity: Connectivity,
+        rule: impl Fn(&S, &[S]) -> S + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            connectivity,
+            rule: Box::new(rule),
+        }
+    }
+}
+
+/// Advances every `GridAutomaton<S>` one generation. Not added by [`crate::GridPlugin`]
+/// automatically -- register it yourself, e.g. `app.add_systems(Update, step_automaton::<bool>)`.
+pub fn step_automaton<S: Clone + Send + Sync + 'static>(
+    automaton_q: Query<(&GridAutomaton<S>, &AttachedCells)>,
+    cells_q: Query<(&GridCell, &CellState<S>)>,
+    mut cells_mut_q: Query<(&GridCell, &mut CellState<S>)>,
+) {
+    for (automaton, attached_cells) in &automaton_q {
+        let previous_generation: HashMap<IVec2, S> = attached_cells
+            .0
+            .iter()
+            .filter_map(|&entity| cells_q.get(entity).ok())
+            .map(|(cell, state)| (cell.coordinate, state.0.clone()))
+            .collect();
+
+        let next_generation: Vec<(Entity, S)> = attached_cells
+            .0
+            .iter()
+            .filter_map(|&entity| {
+                let (cell, _) = cells_q.get(entity).ok()?;
+                let current = previous_generation.get(&cell.coordinate)?;
+                let neighbor_states: Vec<S> = automaton
+                    .connectivity
+                    .offsets()
+                    .iter()
+                    .filter_map(|&offset| previous_generation.get(&(cell.coordinate + offset)))
+                    .cloned()
+                    .collect();
+                Some((entity, (automaton.rule)(current, &neighbor_states)))
+            })
+            .collect();
+
+        for (entity, new_state) in next_generation {
+            if let Ok((_, mut state)) = cells_mut_q.get_mut(entity) {
+                state.0 = new_state;
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::math::{IVec2, Vec2};
+
+/// Coordinate system a [`crate::Grid`] uses to convert between grid coordinates and world-space
+/// offsets. `HexPointy`/`HexFlat` pack axial coordinates `(q, r)` into [`IVec2`] as `(x, y)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridLayout {
+    #[default]
+    Rectangular,
+    HexPointy,
+    HexFlat,
+    Isometric,
+}
+
+impl GridLayout {
+    /// World-space offset of `coordinate`, relative to the grid's own origin.
+    pub(crate) fn cell_offset(self, coordinate: IVec2, cell_size: Vec2, cell_gap: Vec2) -> Vec2 {
+        let c = coordinate.as_vec2();
+        match self {
+            Self::Rectangular => c * (cell_size + cell_gap),
+            Self::HexPointy => Vec2::new(cell_size.x * (c.x + c.y * 0.5), cell_size.y * 0.75 * c.y),
+            Self::HexFlat => Vec2::new(cell_size.x * 0.75 * c.x, cell_size.y * (c.y + c.x * 0.5)),
+            Self::Isometric => Vec2::new(
+                (c.x - c.y) * cell_size.x * 0.5,
+                (c.x + c.y) * cell_size.y * 0.5,
+            ),
+        }
+    }
+
+    /// Inverse of [`Self::cell_offset`]: nearest grid coordinate to `local_translation`.
+    pub(crate) fn coordinate_from_offset(
+        self,
+        local_translation: Vec2,
+        cell_size: Vec2,
+        cell_gap: Vec2,
+    ) -> IVec2 {
+        match self {
+            Self::Rectangular => (local_translation / (cell_size + cell_gap))
+                .round()
+                .as_ivec2(),
+            Self::HexPointy => {
+                let r = local_translation.y / (cell_size.y * 0.75);
+                let q = local_translation.x / cell_size.x - r * 0.5;
+                Self::round_axial(q, r)
+            }
+            Self::HexFlat => {
+                let q = local_translation.x / (cell_size.x * 0.75);
+                let r = local_translation.y / cell_size.y - q * 0.5;
+                Self::round_axial(q, r)
+            }
+            Self::Isometric => {
+                let a = local_translation.x / (cell_size.x * 0.5);
+                let b = local_translation.y / (cell_size.y * 0.5);
+                Vec2::new((a + b) / 2.0, (b - a) / 2.0).round().as_ivec2()
+            }
+        }
+    }
+
+    /// Cube-coordinate rounding of fractional axial `(q, r)` to the nearest hex.
+    #[allow(clippy::cast_possible_truncation, unused_assignments)]
+    fn round_axial(q: f32, r: f32) -> IVec2 {
+        let (x, z) = (q, r);
+        let y = -x - z;
+
+        let mut rx = x.round();
+        let mut ry = y.round();
+        let mut rz = z.round();
+
+        let x_diff = (rx - x).abs();
+        let y_diff = (ry - y).abs();
+        let z_diff = (rz - z).abs();
+
+        if x_diff > y_diff && x_diff > z_diff {
+            rx = -ry - rz;
+        } else if y_diff > z_diff {
+            ry = -rx - rz;
+        } else {
+            rz = -rx - ry;
+        }
+
+        IVec2::new(rx as i32, rz as i32)
+    }
+}
@@ -9,16 +9,25 @@ use bevy::{
 use bevy::{
     app::{App, Plugin, Update},
     ecs::{
-        component::Component,
+        component::{Component, HookContext},
         entity::Entity,
         event::EntityEvent,
         observer::On,
         query::{Changed, Without},
         system::{Commands, Query},
+        world::DeferredWorld,
     },
-    math::{UVec2, Vec2, Vec3},
+    math::{IVec2, UVec2, Vec2, Vec3},
     transform::components::Transform,
 };
+use std::collections::{HashMap, HashSet, VecDeque};
+
+mod animation;
+mod automaton;
+mod layout;
+pub use animation::GridSnapAnimation;
+pub use automaton::{step_automaton, CellState, GridAutomaton};
+pub use layout::GridLayout;
 
 #[derive(Default)]
 pub struct GridPlugin;
@@ -28,7 +37,7 @@ impl Plugin for GridPlugin {
         app.add_observer(UpdateCellPosition::observer)
             .add_observer(SnapCellToGrid::observer)
             .add_observer(TrySnapCellToGrid::observer);
-        app.add_systems(Update, Grid::on_changed);
+        app.add_systems(Update, (Grid::on_changed, animation::animate_snap));
 
         #[cfg(feature = "debug")]
         app.add_systems(Last, Grid::debug_on_changed);
@@ -36,61 +45,154 @@ impl Plugin for GridPlugin {
 }
 
 // Components
-#[derive(Component)]
+#[derive(Component, Default)]
 #[require(AttachedCells, Transform)]
 pub struct Grid {
     pub cell_size: Vec2,
     pub cell_gap: Vec2,
     pub offset: Vec2,
-    pub dimensions: (Option<u32>, Option<u32>),
+    pub dimensions: (AxisBounds, AxisBounds),
+    pub layout: GridLayout,
+    /// Reverse coordinate -> entity index; maintained by the plugin, don't populate by hand.
+    occupancy: HashMap<IVec2, Entity>,
 }
 
 impl Grid {
-    fn get_cell_position(&self, cell: &GridCell) -> Vec3 {
-        (cell.coordinate.as_vec2() * (self.cell_size + self.cell_gap) + self.offset).extend(0.)
+    fn get_cell_position(&self, cell: &GridCell, footprint: Option<&GridFootprint>) -> Vec3 {
+        let covered = footprint_cells(cell.coordinate, footprint);
+        let covered = if covered.is_empty() {
+            vec![cell.coordinate]
+        } else {
+            covered
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let center = covered
+            .iter()
+            .map(|&coordinate| {
+                self.layout
+                    .cell_offset(coordinate, self.cell_size, self.cell_gap)
+            })
+            .sum::<Vec2>()
+            / covered.len() as f32;
+        (center + self.offset).extend(0.)
     }
     fn get_cell_coordinate(
         &self,
         grid_transform: &Transform,
         cell_transform: &Transform,
+        footprint: Option<&GridFootprint>,
         round_to_nearest: bool,
-    ) -> Option<UVec2> {
+    ) -> Option<IVec2> {
         let local_translation =
             (cell_transform.translation - grid_transform.translation).truncate() - self.offset;
 
-        let int_result = (local_translation / (self.cell_gap + self.cell_size))
-            .round()
-            .as_ivec2();
+        let int_result =
+            self.layout
+                .coordinate_from_offset(local_translation, self.cell_size, self.cell_gap);
 
         if round_to_nearest {
-            #[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
-            return Some(UVec2::new(
-                if let Some(dim_x) = self.dimensions.0 {
-                    int_result.x.clamp(0, dim_x as i32)
-                } else {
-                    int_result.x.max(0)
-                } as u32,
-                if let Some(dim_y) = self.dimensions.1 {
-                    int_result.y.clamp(0, dim_y as i32)
-                } else {
-                    int_result.y.max(0)
-                } as u32,
-            ));
+            let coordinate = self.clamp_footprint_anchor(int_result, footprint);
+            return self
+                .is_footprint_valid(coordinate, footprint)
+                .then_some(coordinate);
         }
 
-        if !int_result.x.is_negative()
-            && !int_result.y.is_negative()
-            && self.is_coordinate_valid(int_result.as_uvec2())
-        {
-            Some(int_result.as_uvec2())
+        if self.is_footprint_valid(int_result, footprint) {
+            Some(int_result)
         } else {
             None
         }
     }
-    fn is_coordinate_valid(&self, coordinate: UVec2) -> bool {
-        self.dimensions.0.is_none_or(|width| coordinate.x < width)
-            && self.dimensions.1.is_none_or(|height| coordinate.y < height)
+    fn is_coordinate_valid(&self, coordinate: IVec2) -> bool {
+        self.dimensions.0.contains(coordinate.x) && self.dimensions.1.contains(coordinate.y)
+    }
+    /// Like [`Self::is_coordinate_valid`], for every coordinate a footprint anchored at `origin` covers.
+    fn is_footprint_valid(&self, origin: IVec2, footprint: Option<&GridFootprint>) -> bool {
+        footprint_cells(origin, footprint)
+            .into_iter()
+            .all(|coordinate| self.is_coordinate_valid(coordinate))
+    }
+    /// Clamps the anchor so the whole footprint fits in `dimensions`, not just the anchor cell.
+    fn clamp_footprint_anchor(&self, anchor: IVec2, footprint: Option<&GridFootprint>) -> IVec2 {
+        let offsets = footprint_cells(IVec2::ZERO, footprint);
+        let xs: Vec<i32> = offsets.iter().map(|offset| offset.x).collect();
+        let ys: Vec<i32> = offsets.iter().map(|offset| offset.y).collect();
+
+        IVec2::new(
+            self.dimensions
+                .0
+                .shrink(
+                    xs.iter().copied().min().unwrap_or(0),
+                    xs.iter().copied().max().unwrap_or(0),
+                )
+                .clamp(anchor.x),
+            self.dimensions
+                .1
+                .shrink(
+                    ys.iter().copied().min().unwrap_or(0),
+                    ys.iter().copied().max().unwrap_or(0),
+                )
+                .clamp(anchor.y),
+        )
+    }
+
+    pub fn cell_at(&self, coordinate: IVec2) -> Option<Entity> {
+        self.occupancy.get(&coordinate).copied()
+    }
+    pub fn is_occupied(&self, coordinate: IVec2) -> bool {
+        self.occupancy.contains_key(&coordinate)
+    }
+    pub fn occupied_coordinates(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.occupancy.keys().copied()
+    }
+
+    pub fn neighbors(&self, coordinate: IVec2, connectivity: Connectivity) -> Vec<IVec2> {
+        connectivity
+            .offsets()
+            .iter()
+            .map(|&offset| coordinate + offset)
+            .filter(|&neighbor| self.is_coordinate_valid(neighbor))
+            .collect()
+    }
+    /// Like [`Self::neighbors`], resolved through the occupancy index.
+    pub fn neighbor_entities(
+        &self,
+        coordinate: IVec2,
+        connectivity: Connectivity,
+    ) -> Vec<(IVec2, Option<Entity>)> {
+        self.neighbors(coordinate, connectivity)
+            .into_iter()
+            .map(|neighbor| (neighbor, self.cell_at(neighbor)))
+            .collect()
+    }
+
+    /// Breadth-first search from `start` through 4-connected neighbors satisfying `predicate`.
+    /// Empty if `start` itself doesn't satisfy `predicate`.
+    pub fn flood_fill(&self, start: IVec2, predicate: impl Fn(IVec2) -> bool) -> HashSet<IVec2> {
+        if !predicate(start) {
+            return HashSet::new();
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(coordinate) = queue.pop_front() {
+            for neighbor in self.neighbors(coordinate, Connectivity::VonNeumann) {
+                if predicate(neighbor) && visited.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        visited
+    }
+    pub fn connected_empty_region(&self, start: IVec2) -> HashSet<IVec2> {
+        self.flood_fill(start, |coordinate| !self.is_occupied(coordinate))
+    }
+    pub fn connected_occupied_region(&self, start: IVec2) -> HashSet<IVec2> {
+        self.flood_fill(start, |coordinate| self.is_occupied(coordinate))
     }
+
     fn on_changed(mut commands: Commands, grid_q: Query<&AttachedCells, Changed<Transform>>) {
         for attached_cells in grid_q {
             for &entity in &attached_cells.0 {
@@ -106,7 +208,7 @@ impl Grid {
         cell_outlines_q: Query<Entity, With<DebugCellOutline>>,
     ) {
         use bevy::{
-            color::{Alpha, Color, palettes::tailwind::GREEN_400},
+            color::{palettes::tailwind::GREEN_400, Alpha, Color},
             sprite::Sprite,
             utils::default,
         };
@@ -120,20 +222,18 @@ impl Grid {
                 }
             }
             // Spawn new cell outlines
-            let dimensions = (
-                grid.dimensions.0.unwrap_or(100),
-                grid.dimensions.1.unwrap_or(100),
-            );
+            let x_range = grid.dimensions.0.min..grid.dimensions.0.max.unwrap_or(100);
+            let y_range = grid.dimensions.1.min..grid.dimensions.1.max.unwrap_or(100);
 
             commands.entity(grid_e).with_children(|parent| {
-                for x in 0..dimensions.0 {
-                    for y in 0..dimensions.1 {
-                        #[allow(clippy::cast_precision_loss)]
-                        let transform = Transform::from_xyz(
-                            x as f32 * (grid.cell_size.x + grid.cell_gap.x) + grid.offset.x,
-                            y as f32 * (grid.cell_size.y + grid.cell_gap.y) + grid.offset.y,
-                            0.0,
-                        );
+                for x in x_range.clone() {
+                    for y in y_range.clone() {
+                        let position = grid.layout.cell_offset(
+                            IVec2::new(x, y),
+                            grid.cell_size,
+                            grid.cell_gap,
+                        ) + grid.offset;
+                        let transform = Transform::from_xyz(position.x, position.y, 0.0);
 
                         parent.spawn((
                             DebugCellOutline,
@@ -154,10 +254,125 @@ impl Grid {
 #[derive(Component)]
 struct DebugCellOutline;
 
+/// Bound for one grid axis: `min` inclusive, `max` exclusive. `min` defaults to `0`, but can go
+/// negative so the grid extends below/left of its origin. `From<Option<u32>>` converts an old
+/// `dimensions: (Option<u32>, Option<u32>)` field per-axis, e.g. `(Some(5).into(), None.into())`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AxisBounds {
+    pub min: i32,
+    pub max: Option<i32>,
+}
+
+impl AxisBounds {
+    fn contains(self, value: i32) -> bool {
+        value >= self.min && self.max.is_none_or(|max| value < max)
+    }
+    fn clamp(self, value: i32) -> i32 {
+        self.max
+            .map_or(value, |max| value.min((max - 1).max(self.min)))
+            .max(self.min)
+    }
+    /// Shrinks the bounds so clamping to the result keeps `anchor + min_offset`/`max_offset` in bounds too.
+    fn shrink(self, min_offset: i32, max_offset: i32) -> Self {
+        Self {
+            min: self.min - min_offset,
+            max: self.max.map(|max| max - max_offset),
+        }
+    }
+}
+
+impl Default for AxisBounds {
+    fn default() -> Self {
+        Self { min: 0, max: None }
+    }
+}
+
+impl From<Option<u32>> for AxisBounds {
+    #[allow(clippy::cast_possible_wrap)]
+    fn from(max: Option<u32>) -> Self {
+        Self {
+            min: 0,
+            max: max.map(|max| max as i32),
+        }
+    }
+}
+
+/// Which neighboring coordinates [`Grid::neighbors`] considers adjacent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    /// The 4 orthogonal neighbors (up/down/left/right).
+    #[default]
+    VonNeumann,
+    /// The 8 orthogonal and diagonal neighbors.
+    Moore,
+}
+
+impl Connectivity {
+    const VON_NEUMANN_OFFSETS: [IVec2; 4] = [
+        IVec2::new(0, 1),
+        IVec2::new(1, 0),
+        IVec2::new(0, -1),
+        IVec2::new(-1, 0),
+    ];
+    const MOORE_OFFSETS: [IVec2; 8] = [
+        IVec2::new(0, 1),
+        IVec2::new(1, 1),
+        IVec2::new(1, 0),
+        IVec2::new(1, -1),
+        IVec2::new(0, -1),
+        IVec2::new(-1, -1),
+        IVec2::new(-1, 0),
+        IVec2::new(-1, 1),
+    ];
+
+    pub(crate) fn offsets(self) -> &'static [IVec2] {
+        match self {
+            Self::VonNeumann => &Self::VON_NEUMANN_OFFSETS,
+            Self::Moore => &Self::MOORE_OFFSETS,
+        }
+    }
+}
+
 #[derive(Component, Default)]
 #[require(Transform)]
 pub struct GridCell {
-    pub coordinate: UVec2,
+    pub coordinate: IVec2,
+}
+
+/// A multi-cell footprint a `GridCell` can carry, e.g. a 2x3 piece of furniture.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub enum GridFootprint {
+    /// A `size.x` by `size.y` rectangle anchored at the cell's own coordinate.
+    Rect(UVec2),
+    /// Arbitrary coordinates relative to the cell's own coordinate.
+    Cells(Vec<IVec2>),
+}
+
+impl GridFootprint {
+    fn relative_cells(&self) -> Vec<IVec2> {
+        match self {
+            #[allow(clippy::cast_possible_wrap)]
+            Self::Rect(size) => (0..size.x as i32)
+                .flat_map(|x| (0..size.y as i32).map(move |y| IVec2::new(x, y)))
+                .collect(),
+            Self::Cells(cells) => cells.clone(),
+        }
+    }
+    /// The coordinates this footprint covers when anchored at `origin`.
+    pub fn covered_coordinates(&self, origin: IVec2) -> Vec<IVec2> {
+        self.relative_cells()
+            .into_iter()
+            .map(|offset| origin + offset)
+            .collect()
+    }
+}
+
+/// Coordinates covered by a cell at `origin`: its footprint, or just `origin`.
+fn footprint_cells(origin: IVec2, footprint: Option<&GridFootprint>) -> Vec<IVec2> {
+    footprint.map_or_else(
+        || vec![origin],
+        |footprint| footprint.covered_coordinates(origin),
+    )
 }
 
 // Relationships
@@ -168,8 +383,48 @@ pub struct AttachedCells(Vec<Entity>);
 #[derive(Component)]
 #[require(GridCell)]
 #[relationship(relationship_target = AttachedCells)]
+#[component(on_insert = Self::on_insert, on_remove = Self::on_remove)]
 pub struct AttachedToGrid(pub Entity);
 
+impl AttachedToGrid {
+    /// Registers the cell in its grid's occupancy index, under every coordinate its footprint covers.
+    fn on_insert(mut world: DeferredWorld, context: HookContext) {
+        let entity = context.entity;
+        let Some(coordinate) = world.get::<GridCell>(entity).map(|cell| cell.coordinate) else {
+            return;
+        };
+        let footprint = world.get::<GridFootprint>(entity).cloned();
+        let Some(&AttachedToGrid(grid_entity)) = world.get::<AttachedToGrid>(entity) else {
+            return;
+        };
+        let Some(mut grid) = world.get_mut::<Grid>(grid_entity) else {
+            return;
+        };
+        for coordinate in footprint_cells(coordinate, footprint.as_ref()) {
+            grid.occupancy.insert(coordinate, entity);
+        }
+    }
+    /// Clears the cell's entries from its grid's occupancy index on detach/despawn.
+    fn on_remove(mut world: DeferredWorld, context: HookContext) {
+        let entity = context.entity;
+        let Some(&AttachedToGrid(grid_entity)) = world.get::<AttachedToGrid>(entity) else {
+            return;
+        };
+        let Some(coordinate) = world.get::<GridCell>(entity).map(|cell| cell.coordinate) else {
+            return;
+        };
+        let footprint = world.get::<GridFootprint>(entity).cloned();
+        let Some(mut grid) = world.get_mut::<Grid>(grid_entity) else {
+            return;
+        };
+        for coordinate in footprint_cells(coordinate, footprint.as_ref()) {
+            if grid.occupancy.get(&coordinate) == Some(&entity) {
+                grid.occupancy.remove(&coordinate);
+            }
+        }
+    }
+}
+
 // Events
 #[derive(EntityEvent)]
 pub struct UpdateCellPosition {
@@ -180,20 +435,43 @@ impl UpdateCellPosition {
     #[allow(clippy::needless_pass_by_value)]
     fn observer(
         event: On<Self>,
-        mut grid_cells_q: Query<(&mut Transform, &GridCell, &AttachedToGrid)>,
+        mut commands: Commands,
+        mut grid_cells_q: Query<(
+            &mut Transform,
+            &GridCell,
+            &AttachedToGrid,
+            Option<&GridSnapAnimation>,
+            Option<&GridFootprint>,
+        )>,
         grids_q: Query<(&Grid, &Transform), Without<GridCell>>,
     ) {
-        let Ok((mut cell_transform, cell, grid)) = grid_cells_q.get_mut(event.entity) else {
+        let Ok((mut cell_transform, cell, grid, animation, footprint)) =
+            grid_cells_q.get_mut(event.entity)
+        else {
             return;
         };
         let Ok((grid, grid_transform)) = grids_q.get(grid.0) else {
             return;
         };
-        let cell_position = grid.get_cell_position(cell);
-        cell_transform.translation = grid_transform
+        let cell_position = grid.get_cell_position(cell, footprint);
+        let target = grid_transform
             .translation
             .with_z(cell_transform.translation.z)
             + cell_position;
+
+        if let Some(animation) = animation {
+            commands
+                .entity(event.entity)
+                .insert(animation::AnimatingSnap {
+                    start: cell_transform.translation,
+                    target,
+                    elapsed: std::time::Duration::ZERO,
+                    duration: animation.duration,
+                    ease: animation.ease,
+                });
+        } else {
+            cell_transform.translation = target;
+        }
     }
 }
 #[derive(EntityEvent)]
@@ -205,21 +483,42 @@ impl SnapCellToGrid {
     fn observer(
         event: On<Self>,
         mut commands: Commands,
-        mut grid_cells_q: Query<(&mut GridCell, &Transform, &AttachedToGrid)>,
-        grids_q: Query<(&Grid, &Transform), Without<GridCell>>,
+        mut grid_cells_q: Query<(
+            &mut GridCell,
+            &Transform,
+            &AttachedToGrid,
+            Option<&GridFootprint>,
+        )>,
+        mut grids_q: Query<(&mut Grid, &Transform), Without<GridCell>>,
     ) {
-        let Ok((mut cell, cell_transform, grid)) = grid_cells_q.get_mut(event.entity) else {
+        let Ok((mut cell, cell_transform, grid, footprint)) = grid_cells_q.get_mut(event.entity)
+        else {
             return;
         };
-        let Ok((grid, grid_transform)) = grids_q.get(grid.0) else {
+        let Ok((mut grid, grid_transform)) = grids_q.get_mut(grid.0) else {
             return;
         };
 
-        let Some(coordinate) = grid.get_cell_coordinate(grid_transform, cell_transform, true)
+        let Some(coordinate) =
+            grid.get_cell_coordinate(grid_transform, cell_transform, footprint, true)
         else {
             return;
         };
 
+        let covered = footprint_cells(coordinate, footprint);
+        if covered.iter().any(|&coordinate| {
+            grid.cell_at(coordinate)
+                .is_some_and(|occupant| occupant != event.entity)
+        }) {
+            return;
+        }
+
+        for old in footprint_cells(cell.coordinate, footprint) {
+            grid.occupancy.remove(&old);
+        }
+        for &new in &covered {
+            grid.occupancy.insert(new, event.entity);
+        }
         cell.coordinate = coordinate;
 
         commands.trigger(UpdateCellPosition {
@@ -236,21 +535,42 @@ impl TrySnapCellToGrid {
     fn observer(
         event: On<Self>,
         mut commands: Commands,
-        mut grid_cells_q: Query<(&mut GridCell, &Transform, &AttachedToGrid)>,
-        grids_q: Query<(&Grid, &Transform), Without<GridCell>>,
+        mut grid_cells_q: Query<(
+            &mut GridCell,
+            &Transform,
+            &AttachedToGrid,
+            Option<&GridFootprint>,
+        )>,
+        mut grids_q: Query<(&mut Grid, &Transform), Without<GridCell>>,
     ) {
-        let Ok((mut cell, cell_transform, grid)) = grid_cells_q.get_mut(event.entity) else {
+        let Ok((mut cell, cell_transform, grid, footprint)) = grid_cells_q.get_mut(event.entity)
+        else {
             return;
         };
-        let Ok((grid, grid_transform)) = grids_q.get(grid.0) else {
+        let Ok((mut grid, grid_transform)) = grids_q.get_mut(grid.0) else {
             return;
         };
 
-        let Some(coordinate) = grid.get_cell_coordinate(grid_transform, cell_transform, false)
+        let Some(coordinate) =
+            grid.get_cell_coordinate(grid_transform, cell_transform, footprint, false)
         else {
             return;
         };
 
+        let covered = footprint_cells(coordinate, footprint);
+        if covered.iter().any(|&coordinate| {
+            grid.cell_at(coordinate)
+                .is_some_and(|occupant| occupant != event.entity)
+        }) {
+            return;
+        }
+
+        for old in footprint_cells(cell.coordinate, footprint) {
+            grid.occupancy.remove(&old);
+        }
+        for &new in &covered {
+            grid.occupancy.insert(new, event.entity);
+        }
         cell.coordinate = coordinate;
 
         commands.trigger(UpdateCellPosition {